@@ -0,0 +1,138 @@
+// Pluggable authentication strategies for `Client`.
+use async_trait::async_trait;
+use reqwest::RequestBuilder;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// Attaches credentials to an outgoing request. Implement this to plug in
+/// custom auth (e.g. mutual-TLS client certs) without forking the crate.
+#[async_trait]
+pub trait Authenticate {
+    async fn authenticate(&self, req: RequestBuilder) -> Result<RequestBuilder, Box<dyn std::error::Error>>;
+}
+
+/// Static `X-API-Key` / `X-User-ID` header auth.
+pub struct ApiKeyAuth {
+    pub api_key: String,
+    pub user_id: String,
+}
+
+impl ApiKeyAuth {
+    pub fn new(api_key: String, user_id: String) -> Self {
+        ApiKeyAuth { api_key, user_id }
+    }
+}
+
+#[async_trait]
+impl Authenticate for ApiKeyAuth {
+    async fn authenticate(&self, req: RequestBuilder) -> Result<RequestBuilder, Box<dyn std::error::Error>> {
+        Ok(req.header("X-API-Key", &self.api_key).header("X-User-ID", &self.user_id))
+    }
+}
+
+/// Static bearer token auth.
+pub struct BearerAuth {
+    pub token: String,
+}
+
+impl BearerAuth {
+    pub fn new(token: String) -> Self {
+        BearerAuth { token }
+    }
+}
+
+#[async_trait]
+impl Authenticate for BearerAuth {
+    async fn authenticate(&self, req: RequestBuilder) -> Result<RequestBuilder, Box<dyn std::error::Error>> {
+        Ok(req.bearer_auth(&self.token))
+    }
+}
+
+#[derive(Clone)]
+struct AccessToken {
+    value: String,
+    expires_at: Instant,
+}
+
+impl AccessToken {
+    fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires_at
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    #[allow(dead_code)]
+    token_type: String,
+    expires_in: u64,
+    access_token: String,
+}
+
+/// OAuth2 client-credentials auth. The token is fetched lazily on first use
+/// and transparently refreshed once it expires.
+pub struct OAuthAuth {
+    client_id: String,
+    client_secret: String,
+    token_url: String,
+    http_client: reqwest::Client,
+    cached: Arc<Mutex<Option<AccessToken>>>,
+}
+
+impl OAuthAuth {
+    pub fn new(client_id: String, client_secret: String, token_url: String) -> Self {
+        OAuthAuth {
+            client_id,
+            client_secret,
+            token_url,
+            http_client: reqwest::Client::new(),
+            cached: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    async fn fetch_token(&self) -> Result<AccessToken, Box<dyn std::error::Error>> {
+        let params = [
+            ("grant_type", "client_credentials"),
+            ("client_id", self.client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+
+        let response = self.http_client
+            .post(&self.token_url)
+            .form(&params)
+            .send()
+            .await?;
+
+        let token: TokenResponse = response.json().await?;
+
+        Ok(AccessToken {
+            value: token.access_token,
+            expires_at: Instant::now() + Duration::from_secs(token.expires_in).saturating_sub(TOKEN_EXPIRY_MARGIN),
+        })
+    }
+}
+
+#[async_trait]
+impl Authenticate for OAuthAuth {
+    async fn authenticate(&self, req: RequestBuilder) -> Result<RequestBuilder, Box<dyn std::error::Error>> {
+        let mut guard = self.cached.lock().await;
+        if guard.as_ref().map(AccessToken::is_expired).unwrap_or(true) {
+            *guard = Some(self.fetch_token().await?);
+        }
+        let token = guard.as_ref().expect("token was just populated");
+        Ok(req.bearer_auth(&token.value))
+    }
+}
+
+/// No credentials attached — for public endpoints like `get_tiers`.
+pub struct Unauthenticated;
+
+#[async_trait]
+impl Authenticate for Unauthenticated {
+    async fn authenticate(&self, req: RequestBuilder) -> Result<RequestBuilder, Box<dyn std::error::Error>> {
+        Ok(req)
+    }
+}
@@ -1,85 +1,418 @@
 // Aegis OS Rust SDK
-use reqwest::{Client as HttpClient, header};
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use reqwest::{Client as HttpClient, header, RequestBuilder};
+use serde::{de::DeserializeOwned, Serialize};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+mod auth;
+mod pagination;
+mod retry;
+mod signing;
 pub mod models;
 pub use models::*;
 
+pub use auth::{ApiKeyAuth, Authenticate, BearerAuth, OAuthAuth, Unauthenticated};
+pub use pagination::Page;
+use retry::{backoff_delay, is_retryable_status, retry_after_delay, RetryConfig};
+use signing::hmac_sha256_hex;
+pub use signing::verify_webhook_signature;
+
 #[derive(Clone)]
 pub struct Client {
     base_url: String,
-    api_key: String,
-    user_id: String,
+    auth: Arc<dyn Authenticate + Send + Sync>,
+    signing_secret: Option<String>,
     http_client: HttpClient,
+    retry_config: RetryConfig,
 }
 
 impl Client {
     pub fn new(base_url: String, api_key: String, user_id: String) -> Self {
         Client {
             base_url,
-            api_key,
-            user_id,
+            auth: Arc::new(ApiKeyAuth::new(api_key, user_id)),
+            signing_secret: None,
+            http_client: HttpClient::new(),
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    /// Client-credentials OAuth2 flow. The access token is fetched lazily on
+    /// first request and transparently refreshed once it expires.
+    pub fn with_oauth(base_url: String, client_id: String, client_secret: String, token_url: String) -> Self {
+        Client {
+            base_url,
+            auth: Arc::new(OAuthAuth::new(client_id, client_secret, token_url)),
+            signing_secret: None,
+            http_client: HttpClient::new(),
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    /// Signs each request with `HMAC-SHA256(secret_key, canonical_string + timestamp)`,
+    /// for endpoints that reject unsigned mutations.
+    pub fn with_signing(base_url: String, api_key: String, secret_key: String, user_id: String) -> Self {
+        Client {
+            base_url,
+            auth: Arc::new(ApiKeyAuth::new(api_key, user_id)),
+            signing_secret: Some(secret_key),
             http_client: HttpClient::new(),
+            retry_config: RetryConfig::default(),
         }
     }
 
-    async fn do_request<T: Serialize>(
+    /// Constructs a `Client` with a custom [`Authenticate`] strategy, e.g. for
+    /// mutual-TLS client certs or other credentials this crate doesn't model.
+    pub fn with_auth(base_url: String, auth: Arc<dyn Authenticate + Send + Sync>) -> Self {
+        Client {
+            base_url,
+            auth,
+            signing_secret: None,
+            http_client: HttpClient::new(),
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    /// Returns a [`ClientBuilder`] for configuring timeouts, retries, and the
+    /// user agent before constructing a `Client`.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    fn sign(&self, request: RequestBuilder, method: &str, url: &str, body_json: Option<&str>, secret_key: &str) -> Result<RequestBuilder, Box<dyn std::error::Error>> {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        let canonical = match (method, body_json) {
+            ("POST", Some(body)) | ("PATCH", Some(body)) => format!("{}timestamp={}", body, timestamp),
+            _ => {
+                let query = url.split_once('?').map(|(_, q)| q).unwrap_or("");
+                format!("{}timestamp={}", query, timestamp)
+            }
+        };
+        let signature = hmac_sha256_hex(secret_key, &canonical)?;
+        Ok(request
+            .header("X-Timestamp", timestamp.to_string())
+            .header("X-Signature", signature))
+    }
+
+    async fn build_request(
+        &self,
+        method: &str,
+        url: &str,
+        body_json: Option<&str>,
+        auth: &(dyn Authenticate + Send + Sync),
+    ) -> Result<RequestBuilder, Box<dyn std::error::Error>> {
+        let mut request = match method {
+            "POST" => self.http_client.post(url),
+            "PATCH" => self.http_client.patch(url),
+            "DELETE" => self.http_client.delete(url),
+            _ => self.http_client.get(url),
+        };
+        request = request.header("Content-Type", "application/json");
+        request = auth.authenticate(request).await?;
+
+        if let Some(secret_key) = &self.signing_secret {
+            request = self.sign(request, method, url, body_json, secret_key)?;
+        }
+        if let Some(body) = body_json {
+            request = request.body(body.to_string());
+        }
+
+        Ok(request)
+    }
+
+    async fn do_request_raw<T: Serialize>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<T>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        self.do_request_raw_as(method, endpoint, body, self.auth.as_ref()).await
+    }
+
+    async fn do_request_raw_as<T: Serialize>(
         &self,
         method: &str,
         endpoint: &str,
         body: Option<T>,
+        auth: &(dyn Authenticate + Send + Sync),
     ) -> Result<String, Box<dyn std::error::Error>> {
         let url = format!("{}{}", self.base_url, endpoint);
-        
-        let mut headers = header::HeaderMap::new();
-        headers.insert("Content-Type", "application/json".parse()?);
-        headers.insert("X-API-Key", self.api_key.parse()?);
-        headers.insert("X-User-ID", self.user_id.parse()?);
-
-        let response = match method {
-            "POST" => {
-                let body_json = serde_json::to_string(&body)?;
-                self.http_client
-                    .post(&url)
-                    .headers(headers)
-                    .body(body_json)
-                    .send()
-                    .await?
-            }
-            _ => self.http_client
-                .get(&url)
-                .headers(headers)
-                .send()
-                .await?,
+        let body_json = match method {
+            "POST" | "PATCH" => Some(serde_json::to_string(&body)?),
+            _ => None,
         };
 
+        let response = self.send_with_retries(method, &url, body_json.as_deref(), auth).await?;
         Ok(response.text().await?)
     }
 
-    pub async fn validate_license(&self, key: &str) -> Result<String, Box<dyn std::error::Error>> {
+    /// Sends the request, retrying on connection errors and on 429/502/503/504
+    /// responses with exponential backoff and full jitter. Non-idempotent POSTs
+    /// only retry on connection-level failures, never on a received response,
+    /// to avoid duplicate mutations.
+    async fn send_with_retries(
+        &self,
+        method: &str,
+        url: &str,
+        body_json: Option<&str>,
+        auth: &(dyn Authenticate + Send + Sync),
+    ) -> Result<reqwest::Response, Box<dyn std::error::Error>> {
+        let is_idempotent = method != "POST";
+        let mut attempt = 0;
+
+        loop {
+            let request = self.build_request(method, url, body_json, auth).await?;
+
+            match request.send().await {
+                Ok(response) => {
+                    let retryable = is_idempotent
+                        && is_retryable_status(response.status().as_u16())
+                        && attempt < self.retry_config.max_retries;
+                    if !retryable {
+                        return Ok(response);
+                    }
+                    let delay = retry_after_delay(response.headers()).unwrap_or_else(|| backoff_delay(attempt));
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                Err(e) => {
+                    if attempt >= self.retry_config.max_retries {
+                        return Err(Box::new(e));
+                    }
+                    tokio::time::sleep(backoff_delay(attempt)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    async fn do_request<T: Serialize, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<T>,
+    ) -> Result<R, Box<dyn std::error::Error>> {
+        let text = self.do_request_raw(method, endpoint, body).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    async fn do_request_as<T: Serialize, R: DeserializeOwned>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        body: Option<T>,
+        auth: &(dyn Authenticate + Send + Sync),
+    ) -> Result<R, Box<dyn std::error::Error>> {
+        let text = self.do_request_raw_as(method, endpoint, body, auth).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    pub async fn validate_license(&self, key: &str) -> Result<License, Box<dyn std::error::Error>> {
         #[derive(Serialize)]
         struct Request {
             key: String,
         }
-        
+
         let body = Request {
             key: key.to_string(),
         };
-        
+
         self.do_request("POST", "/api/v1/license/validate", Some(body))
             .await
     }
 
-    pub async fn get_tiers(&self) -> Result<String, Box<dyn std::error::Error>> {
-        self.do_request::<String>("GET", "/api/v1/tiers", None).await
+    pub async fn validate_license_raw(&self, key: &str) -> Result<String, Box<dyn std::error::Error>> {
+        #[derive(Serialize)]
+        struct Request {
+            key: String,
+        }
+
+        let body = Request {
+            key: key.to_string(),
+        };
+
+        self.do_request_raw("POST", "/api/v1/license/validate", Some(body))
+            .await
+    }
+
+    /// Lists the available license tiers. This is a public endpoint — it
+    /// always runs credential-free, regardless of how the `Client` was
+    /// constructed.
+    pub async fn get_tiers(&self) -> Result<Vec<License>, Box<dyn std::error::Error>> {
+        self.do_request_as::<String, Vec<License>>("GET", "/api/v1/tiers", None, &Unauthenticated)
+            .await
+    }
+
+    pub async fn get_tiers_raw(&self) -> Result<String, Box<dyn std::error::Error>> {
+        self.do_request_raw_as::<String>("GET", "/api/v1/tiers", None, &Unauthenticated)
+            .await
+    }
+
+    pub async fn get_system_status(&self) -> Result<SystemStatus, Box<dyn std::error::Error>> {
+        self.do_request::<String, SystemStatus>("GET", "/api/v1/system/status", None).await
+    }
+
+    pub async fn get_system_status_raw(&self) -> Result<String, Box<dyn std::error::Error>> {
+        self.do_request_raw::<String>("GET", "/api/v1/system/status", None).await
+    }
+
+    pub async fn get_security_check(&self) -> Result<SecurityCheck, Box<dyn std::error::Error>> {
+        self.do_request::<String, SecurityCheck>("GET", "/api/v1/security/check", None).await
+    }
+
+    pub async fn get_security_check_raw(&self) -> Result<String, Box<dyn std::error::Error>> {
+        self.do_request_raw::<String>("GET", "/api/v1/security/check", None).await
     }
 
-    pub async fn get_system_status(&self) -> Result<String, Box<dyn std::error::Error>> {
-        self.do_request::<String>("GET", "/api/v1/system/status", None).await
+    pub async fn create_webhook(&self, url: &str, events: Vec<String>) -> Result<Webhook, Box<dyn std::error::Error>> {
+        #[derive(Serialize)]
+        struct Request {
+            url: String,
+            events: Vec<String>,
+        }
+
+        let body = Request {
+            url: url.to_string(),
+            events,
+        };
+
+        self.do_request("POST", "/api/v1/webhooks", Some(body)).await
+    }
+
+    pub async fn list_webhooks(&self) -> Result<Vec<Webhook>, Box<dyn std::error::Error>> {
+        self.do_request::<String, Vec<Webhook>>("GET", "/api/v1/webhooks", None).await
+    }
+
+    pub async fn update_webhook(&self, webhook_id: &str, active: bool) -> Result<Webhook, Box<dyn std::error::Error>> {
+        #[derive(Serialize)]
+        struct Request {
+            active: bool,
+        }
+
+        let body = Request { active };
+
+        self.do_request("PATCH", &format!("/api/v1/webhooks/{}", webhook_id), Some(body))
+            .await
     }
 
-    pub async fn get_security_check(&self) -> Result<String, Box<dyn std::error::Error>> {
-        self.do_request::<String>("GET", "/api/v1/security/check", None).await
+    pub async fn delete_webhook(&self, webhook_id: &str) -> Result<(), Box<dyn std::error::Error>> {
+        self.do_request_raw::<()>("DELETE", &format!("/api/v1/webhooks/{}", webhook_id), None)
+            .await?;
+        Ok(())
+    }
+
+    pub(crate) async fn fetch_page<T: DeserializeOwned>(&self, url: &str) -> Result<Page<T>, Box<dyn std::error::Error>> {
+        let response = self.send_with_retries("GET", url, None, self.auth.as_ref()).await?;
+
+        let (next_url, prev_url) = response
+            .headers()
+            .get(header::LINK)
+            .and_then(|v| v.to_str().ok())
+            .map(pagination::parse_link_header)
+            .unwrap_or((None, None));
+
+        let items: Vec<T> = response.json().await?;
+        Ok(Page { items, next_url, prev_url })
+    }
+
+    pub async fn get_paged<T: DeserializeOwned>(&self, endpoint: &str) -> Result<Page<T>, Box<dyn std::error::Error>> {
+        self.fetch_page(&format!("{}{}", self.base_url, endpoint)).await
+    }
+
+    pub fn items_stream<'a, T: DeserializeOwned + 'a>(
+        &'a self,
+        endpoint: &str,
+    ) -> impl futures::Stream<Item = Result<T, Box<dyn std::error::Error>>> + 'a {
+        pagination::items_stream(self, format!("{}{}", self.base_url, endpoint))
+    }
+}
+
+/// Configures timeouts, retry policy, and the user agent before constructing
+/// a [`Client`]. Obtained via [`Client::builder`].
+#[derive(Default)]
+pub struct ClientBuilder {
+    timeout: Option<Duration>,
+    max_retries: u32,
+    user_agent: Option<String>,
+}
+
+impl ClientBuilder {
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: String) -> Self {
+        self.user_agent = Some(user_agent);
+        self
+    }
+
+    fn build_http_client(&self) -> Result<HttpClient, Box<dyn std::error::Error>> {
+        let mut builder = HttpClient::builder();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.clone());
+        }
+        Ok(builder.build()?)
+    }
+
+    pub fn build(self, base_url: String, api_key: String, user_id: String) -> Result<Client, Box<dyn std::error::Error>> {
+        Ok(Client {
+            base_url,
+            auth: Arc::new(ApiKeyAuth::new(api_key, user_id)),
+            signing_secret: None,
+            http_client: self.build_http_client()?,
+            retry_config: RetryConfig { max_retries: self.max_retries },
+        })
+    }
+
+    pub fn build_oauth(
+        self,
+        base_url: String,
+        client_id: String,
+        client_secret: String,
+        token_url: String,
+    ) -> Result<Client, Box<dyn std::error::Error>> {
+        Ok(Client {
+            base_url,
+            auth: Arc::new(OAuthAuth::new(client_id, client_secret, token_url)),
+            signing_secret: None,
+            http_client: self.build_http_client()?,
+            retry_config: RetryConfig { max_retries: self.max_retries },
+        })
+    }
+
+    pub fn build_signing(
+        self,
+        base_url: String,
+        api_key: String,
+        secret_key: String,
+        user_id: String,
+    ) -> Result<Client, Box<dyn std::error::Error>> {
+        Ok(Client {
+            base_url,
+            auth: Arc::new(ApiKeyAuth::new(api_key, user_id)),
+            signing_secret: Some(secret_key),
+            http_client: self.build_http_client()?,
+            retry_config: RetryConfig { max_retries: self.max_retries },
+        })
+    }
+
+    /// Builds a `Client` with a custom [`Authenticate`] strategy.
+    pub fn build_with_auth(self, base_url: String, auth: Arc<dyn Authenticate + Send + Sync>) -> Result<Client, Box<dyn std::error::Error>> {
+        Ok(Client {
+            base_url,
+            auth,
+            signing_secret: None,
+            http_client: self.build_http_client()?,
+            retry_config: RetryConfig { max_retries: self.max_retries },
+        })
     }
 }
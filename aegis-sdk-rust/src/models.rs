@@ -34,3 +34,10 @@ pub struct SystemStatus {
     pub version: String,
     pub editions: Vec<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SecurityCheck {
+    pub status: String,
+    pub issues: Vec<String>,
+    pub last_scan: String,
+}
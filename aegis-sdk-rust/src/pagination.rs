@@ -0,0 +1,118 @@
+// Cursor/Link-header pagination for list endpoints.
+use crate::Client;
+use futures::Stream;
+use serde::de::DeserializeOwned;
+
+/// One page of results from a list endpoint, with the adjacent-page URLs
+/// parsed out of the response's RFC-5988 `Link` header.
+#[derive(Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_url: Option<String>,
+    pub prev_url: Option<String>,
+}
+
+impl<T: DeserializeOwned> Page<T> {
+    pub async fn next(&self, client: &Client) -> Result<Option<Page<T>>, Box<dyn std::error::Error>> {
+        match &self.next_url {
+            Some(url) => Ok(Some(client.fetch_page(url).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn prev(&self, client: &Client) -> Result<Option<Page<T>>, Box<dyn std::error::Error>> {
+        match &self.prev_url {
+            Some(url) => Ok(Some(client.fetch_page(url).await?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Parses the comma-separated `<url>; rel="next", <url>; rel="prev"` format
+/// into `(next_url, prev_url)`.
+pub(crate) fn parse_link_header(header_value: &str) -> (Option<String>, Option<String>) {
+    let mut next_url = None;
+    let mut prev_url = None;
+
+    for link in header_value.split(',') {
+        let mut parts = link.split(';');
+        let Some(url_part) = parts.next() else { continue };
+        let url = url_part.trim().trim_start_matches('<').trim_end_matches('>');
+
+        for param in parts {
+            let param = param.trim();
+            if param == "rel=\"next\"" {
+                next_url = Some(url.to_string());
+            } else if param == "rel=\"prev\"" {
+                prev_url = Some(url.to_string());
+            }
+        }
+    }
+
+    (next_url, prev_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_next_and_prev() {
+        let header = r#"<https://api.example.com/v1/users?cursor=abc>; rel="next", <https://api.example.com/v1/users?cursor=xyz>; rel="prev""#;
+        let (next_url, prev_url) = parse_link_header(header);
+        assert_eq!(next_url.as_deref(), Some("https://api.example.com/v1/users?cursor=abc"));
+        assert_eq!(prev_url.as_deref(), Some("https://api.example.com/v1/users?cursor=xyz"));
+    }
+
+    #[test]
+    fn parses_next_only() {
+        let header = r#"<https://api.example.com/v1/users?cursor=abc>; rel="next""#;
+        let (next_url, prev_url) = parse_link_header(header);
+        assert_eq!(next_url.as_deref(), Some("https://api.example.com/v1/users?cursor=abc"));
+        assert_eq!(prev_url, None);
+    }
+
+    #[test]
+    fn ignores_unrecognized_rels() {
+        let header = r#"<https://api.example.com/v1/users?cursor=abc>; rel="first""#;
+        assert_eq!(parse_link_header(header), (None, None));
+    }
+
+    #[test]
+    fn empty_header_yields_no_links() {
+        assert_eq!(parse_link_header(""), (None, None));
+    }
+}
+
+struct StreamState<T> {
+    next_url: Option<String>,
+    buffered: std::vec::IntoIter<T>,
+}
+
+/// Lazily walks `next` links, yielding individual items as they're fetched.
+pub(crate) fn items_stream<'a, T: DeserializeOwned + 'a>(
+    client: &'a Client,
+    initial_url: String,
+) -> impl Stream<Item = Result<T, Box<dyn std::error::Error>>> + 'a {
+    futures::stream::unfold(
+        StreamState {
+            next_url: Some(initial_url),
+            buffered: Vec::new().into_iter(),
+        },
+        move |mut state| async move {
+            loop {
+                if let Some(item) = state.buffered.next() {
+                    return Some((Ok(item), state));
+                }
+                let url = state.next_url.take()?;
+                match client.fetch_page::<T>(&url).await {
+                    Ok(page) => {
+                        state.next_url = page.next_url;
+                        state.buffered = page.items.into_iter();
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        },
+    )
+}
@@ -0,0 +1,98 @@
+// Retry/backoff policy for transient failures.
+use rand::Rng;
+use reqwest::header;
+use std::time::{Duration, SystemTime};
+
+const BASE_DELAY_MS: u64 = 200;
+const CAP_MS: u64 = 10_000;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RetryConfig {
+    pub max_retries: u32,
+}
+
+pub(crate) fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 429 | 502 | 503 | 504)
+}
+
+/// Exponential backoff with full jitter: `rand(0, min(cap, base * 2^attempt))`.
+pub(crate) fn backoff_delay(attempt: u32) -> Duration {
+    let exp = 1u64.checked_shl(attempt).unwrap_or(u64::MAX);
+    let max_ms = BASE_DELAY_MS.saturating_mul(exp).min(CAP_MS);
+    let jittered_ms = rand::thread_rng().gen_range(0..=max_ms);
+    Duration::from_millis(jittered_ms)
+}
+
+/// Honors a `Retry-After` header, either as delay-seconds or an HTTP-date.
+pub(crate) fn retry_after_delay(headers: &header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+    date.duration_since(SystemTime::now()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retryable_statuses_match_spec() {
+        for status in [429, 502, 503, 504] {
+            assert!(is_retryable_status(status));
+        }
+        for status in [200, 400, 401, 404, 500, 501] {
+            assert!(!is_retryable_status(status));
+        }
+    }
+
+    #[test]
+    fn backoff_delay_never_exceeds_the_cap() {
+        for attempt in 0..20 {
+            assert!(backoff_delay(attempt) <= Duration::from_millis(CAP_MS));
+        }
+    }
+
+    #[test]
+    fn backoff_delay_grows_with_attempt_before_capping() {
+        // The theoretical max at attempt 0 is BASE_DELAY_MS; at attempt 5 it is
+        // BASE_DELAY_MS * 32, still below the cap, so repeated sampling should
+        // be able to exceed that initial bound.
+        let exceeded_initial_bound = (0..200).any(|_| backoff_delay(5) > Duration::from_millis(BASE_DELAY_MS));
+        assert!(exceeded_initial_bound);
+    }
+
+    #[test]
+    fn retry_after_missing_header_returns_none() {
+        let headers = header::HeaderMap::new();
+        assert_eq!(retry_after_delay(&headers), None);
+    }
+
+    #[test]
+    fn retry_after_parses_delay_seconds() {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::RETRY_AFTER, "120".parse().unwrap());
+        assert_eq!(retry_after_delay(&headers), Some(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn retry_after_parses_http_date() {
+        let target = SystemTime::now() + Duration::from_secs(60);
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::RETRY_AFTER, httpdate::fmt_http_date(target).parse().unwrap());
+
+        let delay = retry_after_delay(&headers).expect("http-date should parse");
+        // HTTP-date has one-second resolution, so allow a little slack.
+        assert!(delay.as_secs() >= 58 && delay.as_secs() <= 61);
+    }
+
+    #[test]
+    fn retry_after_rejects_garbage_value() {
+        let mut headers = header::HeaderMap::new();
+        headers.insert(header::RETRY_AFTER, "not-a-valid-value".parse().unwrap());
+        assert_eq!(retry_after_delay(&headers), None);
+    }
+}
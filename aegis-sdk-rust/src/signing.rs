@@ -0,0 +1,71 @@
+// HMAC-SHA256 request signing helpers.
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Computes `HMAC-SHA256(secret, message)` and hex-encodes the digest.
+pub(crate) fn hmac_sha256_hex(secret: &str, message: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())?;
+    mac.update(message.as_bytes());
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Verifies an inbound webhook delivery by recomputing `HMAC-SHA256(secret, payload)`
+/// and comparing it against `header_value` (hex-encoded) in constant time.
+pub fn verify_webhook_signature(secret: &str, payload: &[u8], header_value: &str) -> bool {
+    let Ok(mut mac) = HmacSha256::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(payload);
+
+    let Ok(signature_bytes) = hex::decode(header_value) else {
+        return false;
+    };
+
+    mac.verify_slice(&signature_bytes).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hmac_sha256_hex_is_deterministic_and_hex_encoded() {
+        let a = hmac_sha256_hex("secret", "timestamp=1").unwrap();
+        let b = hmac_sha256_hex("secret", "timestamp=1").unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.len(), 64);
+        assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    #[test]
+    fn hmac_sha256_hex_differs_on_message_or_secret() {
+        let base = hmac_sha256_hex("secret", "timestamp=1").unwrap();
+        assert_ne!(base, hmac_sha256_hex("secret", "timestamp=2").unwrap());
+        assert_ne!(base, hmac_sha256_hex("other-secret", "timestamp=1").unwrap());
+    }
+
+    #[test]
+    fn verify_webhook_signature_accepts_matching_signature() {
+        let signature = hmac_sha256_hex("secret", "payload").unwrap();
+        assert!(verify_webhook_signature("secret", b"payload", &signature));
+    }
+
+    #[test]
+    fn verify_webhook_signature_rejects_tampered_payload() {
+        let signature = hmac_sha256_hex("secret", "payload").unwrap();
+        assert!(!verify_webhook_signature("secret", b"tampered", &signature));
+    }
+
+    #[test]
+    fn verify_webhook_signature_rejects_wrong_secret() {
+        let signature = hmac_sha256_hex("secret", "payload").unwrap();
+        assert!(!verify_webhook_signature("wrong-secret", b"payload", &signature));
+    }
+
+    #[test]
+    fn verify_webhook_signature_rejects_malformed_header() {
+        assert!(!verify_webhook_signature("secret", b"payload", "not-hex!"));
+    }
+}